@@ -0,0 +1,108 @@
+//! Typed decoding of [`Receipt`] outputs and logs, so callers can read return
+//! values of invoked methods programmatically instead of pattern-matching
+//! opaque strings - similar in spirit to solana-transaction-status's parsed
+//! instruction/account views.
+
+use sbor::any::Value;
+use sbor::{decode_any, Decode, DecodeError};
+use scrypto::buffer::scrypto_decode;
+
+use crate::provider::Receipt;
+
+/// A single decoded entry from `Receipt.outputs`: the Scrypto value it
+/// SBOR-decodes to, for callers who want to inspect the shape of a return
+/// value without committing to a concrete Rust type via [`Receipt::output_as`].
+#[derive(Clone, Debug)]
+pub enum DecodedOutput {
+    /// The output hex-decoded and then SBOR-decoded successfully.
+    Decoded(Value),
+    /// The output string wasn't valid hex or wasn't valid SBOR, so it
+    /// couldn't be interpreted as a Scrypto value at all.
+    Unparseable(String),
+}
+
+/// The severity of a single log line, as emitted by the engine.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Level {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    fn parse(raw: &str) -> Option<Level> {
+        match raw.to_uppercase().as_str() {
+            "ERROR" => Some(Level::Error),
+            "WARN" => Some(Level::Warn),
+            "INFO" => Some(Level::Info),
+            "DEBUG" => Some(Level::Debug),
+            "TRACE" => Some(Level::Trace),
+            _ => None,
+        }
+    }
+}
+
+impl Receipt {
+    /// Whether the transaction committed successfully.
+    pub fn is_success(&self) -> bool {
+        self.status.eq_ignore_ascii_case("success")
+    }
+
+    /// Decodes every entry in `outputs` into a [`DecodedOutput`], without
+    /// committing to a concrete Rust type for any of them.
+    pub fn decoded_outputs(&self) -> Vec<DecodedOutput> {
+        self.outputs
+            .iter()
+            .map(|output| match hex::decode(output).ok().and_then(|bytes| decode_any(&bytes).ok()) {
+                Some(value) => DecodedOutput::Decoded(value),
+                None => DecodedOutput::Unparseable(output.clone()),
+            })
+            .collect()
+    }
+
+    /// SBOR-decodes `outputs[index]` as `T`.
+    pub fn output_as<T: Decode>(&self, index: usize) -> Result<T, OutputDecodeError> {
+        let output = self
+            .outputs
+            .get(index)
+            .ok_or(OutputDecodeError::IndexOutOfBounds(index))?;
+        let bytes = hex::decode(output).map_err(|_| OutputDecodeError::NotHex(output.clone()))?;
+        scrypto_decode(&bytes).map_err(OutputDecodeError::Sbor)
+    }
+
+    /// Splits each entry in `logs` into its `(Level, message)` pair. Lines that
+    /// don't follow the `LEVEL message` convention are reported at `Level::Info`.
+    pub fn logs(&self) -> Vec<(Level, String)> {
+        self.logs
+            .iter()
+            .map(|line| match line.split_once(' ') {
+                Some((level, message)) if Level::parse(level).is_some() => {
+                    (Level::parse(level).unwrap(), message.to_string())
+                }
+                _ => (Level::Info, line.clone()),
+            })
+            .collect()
+    }
+}
+
+/// Errors which can occur while decoding a typed value out of `Receipt.outputs`.
+#[derive(Debug)]
+pub enum OutputDecodeError {
+    IndexOutOfBounds(usize),
+    NotHex(String),
+    Sbor(DecodeError),
+}
+
+impl std::fmt::Display for OutputDecodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OutputDecodeError::IndexOutOfBounds(index) => write!(f, "output index {} is out of bounds", index),
+            OutputDecodeError::NotHex(output) => write!(f, "output is not valid hex: {}", output),
+            OutputDecodeError::Sbor(error) => write!(f, "failed to SBOR-decode output: {:?}", error),
+        }
+    }
+}
+
+impl std::error::Error for OutputDecodeError {}
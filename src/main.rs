@@ -5,17 +5,23 @@ use scrypto::prelude::*;
 
 use std::io::Read;
 
-// Used to handle the JSON serialization and deserialization
-use serde::{Deserialize, Serialize};
-
 mod utils;
 use utils::{DecompileError, decompile};
 
+mod provider;
+use provider::{Nonce, PteHttpProvider, Receipt, Signature, TransactionBody};
+
+mod middleware;
+
+mod signer;
+
+mod decode;
+
 fn main() {
     let f = std::fs::File::open("2mb_package.wasm").unwrap();
     let mut reader = std::io::BufReader::new(f);
     let mut buffer: Vec<u8> = Vec::new();
-    
+
     // Read file into vector.
     reader.read_to_end(&mut buffer).unwrap();
 
@@ -33,9 +39,30 @@ fn main() {
 // Additional code required to support the above function
 // =====================================================================================================================
 
-
-/// Submits the transaction to the PTE01 server.
+/// Submits the transaction to the PTE01 server, without waiting for it to be confirmed.
+///
+/// A thin wrapper around [`submit_transaction_and_confirm`]'s submission step,
+/// run to completion on a throwaway tokio runtime; reach for `provider.submit(..)`
+/// directly if you want to customize the endpoint or stack middleware.
 pub fn submit_transaction(transaction: &SignedTransaction) -> Result<Receipt, TransactionSubmissionError> {
+    let transaction_body = to_transaction_body(transaction)?;
+    tokio::runtime::Runtime::new()
+        .expect("failed to start a tokio runtime")
+        .block_on(async { Ok(PteHttpProvider::pte01().submit_async(&transaction_body).await?) })
+}
+
+/// Submits the transaction to the PTE01 server and polls for its receipt until
+/// `status` is terminal, on an exponential-backoff loop, or until `timeout` elapses.
+pub async fn submit_transaction_and_confirm(
+    transaction: &SignedTransaction,
+    timeout: std::time::Duration,
+) -> Result<Receipt, TransactionSubmissionError> {
+    let transaction_body = to_transaction_body(transaction)?;
+    Ok(PteHttpProvider::pte01().submit_and_confirm(&transaction_body, timeout).await?)
+}
+
+/// Builds the wire-format [`TransactionBody`] the PTE API expects out of a `SignedTransaction`.
+pub fn to_transaction_body(transaction: &SignedTransaction) -> Result<TransactionBody, TransactionSubmissionError> {
     // Getting the nonce used in the transaction from the transaction object itself
     let nonce: u64 = {
         let nonce_instructions: Vec<Instruction> = transaction.transaction.instructions
@@ -51,14 +78,14 @@ pub fn submit_transaction(transaction: &SignedTransaction) -> Result<Receipt, Tr
 
         if nonce_instructions.len() == 0 {
             Err(TransactionSubmissionError::NoNonceFound)
-        } 
-        else if nonce_instructions.len() == 1{ 
+        }
+        else if nonce_instructions.len() == 1{
             if let Instruction::Nonce { nonce } = nonce_instructions[0] {
                 Ok(nonce)
             } else {
                 panic!("Expected a nonce");
             }
-        } 
+        }
         else {
             Err(TransactionSubmissionError::MultipleNonceFound)
         }
@@ -68,85 +95,17 @@ pub fn submit_transaction(transaction: &SignedTransaction) -> Result<Receipt, Tr
     let signatures: Vec<Signature> = transaction.signatures
         .iter()
         .map(|x| Signature{
-            public_key: x.0.to_string(), 
+            public_key: x.0.to_string(),
             signature: x.1.to_string()
         })
         .collect();
 
     // Creating the transaction body object which is what will be submitted to the PTE
-    let transaction_body: TransactionBody = TransactionBody {
+    Ok(TransactionBody {
         manifest: decompile(&transaction.transaction)?,
         nonce: nonce,
         signatures: signatures
-    };
-
-    // Submitting the transaction to the PTE's `/transaction` endpoint
-    let response = reqwest::blocking::Client::new()
-        .post("https://pte01.radixdlt.com/transaction")
-        .json(&transaction_body)
-        .send()?;
-
-    let response_body: String = response.text().unwrap();
-    if let Ok(receipt) = serde_json::from_str(&response_body) {
-        return Ok(receipt)
-    } else {
-        return Err(TransactionSubmissionError::JsonDeserializationError(format!("Can not deserialize string: {}", response_body)));
-    };
-}
-
-/// A struct which describes the Nonce. Required for the TransactionBody struct
-#[derive(Serialize, Deserialize, Debug)]
-pub struct Nonce {
-    value: u64,
-}
-
-/// A struct which defines the signature used in the TransactionBody struct.
-#[derive(Serialize, Deserialize, Debug)]
-pub struct Signature {
-    public_key: String,
-    signature: String,
-}
-
-/// A struct which defines the transaction payload that the PTE's API accepts.
-#[derive(Serialize, Deserialize, Debug)]
-pub struct TransactionBody {
-    manifest: String,
-    nonce: Nonce,
-    signatures: Vec<Signature>,
-}
-
-#[derive(Serialize, Deserialize, Debug)]
-pub struct Receipt {
-    pub transaction_hash: String,
-    pub status: String,
-    pub outputs: Vec<String>,
-    pub logs: Vec<String>,
-    pub new_packages: Vec<String>,
-    pub new_components: Vec<String>,
-    pub new_resources: Vec<String>,
-}
-
-impl Receipt {
-    pub fn new_packages(&self) -> Vec<PackageAddress> {
-        return self.new_packages
-            .iter()
-            .map(|x| PackageAddress::from_str(x).unwrap())
-            .collect()
-    }
-    
-    pub fn new_components(&self) -> Vec<ComponentAddress> {
-        return self.new_components
-            .iter()
-            .map(|x| ComponentAddress::from_str(x).unwrap())
-            .collect()
-    }
-    
-    pub fn new_resources(&self) -> Vec<ResourceAddress> {
-        return self.new_resources
-            .iter()
-            .map(|x| ResourceAddress::from_str(x).unwrap())
-            .collect()
-    }
+    })
 }
 
 /// An enum of the errors which could occur when submitting a transaction to the PTE API.
@@ -155,8 +114,7 @@ pub enum TransactionSubmissionError {
     NoNonceFound,
     MultipleNonceFound,
     DecompileError(DecompileError),
-    HttpRequestError(reqwest::Error),
-    JsonDeserializationError(String)
+    Provider(provider::ProviderError),
 }
 
 impl From<utils::DecompileError> for TransactionSubmissionError {
@@ -165,8 +123,8 @@ impl From<utils::DecompileError> for TransactionSubmissionError {
     }
 }
 
-impl From<reqwest::Error> for TransactionSubmissionError {
-    fn from(error: reqwest::Error) -> TransactionSubmissionError {
-        TransactionSubmissionError::HttpRequestError(error)
+impl From<provider::ProviderError> for TransactionSubmissionError {
+    fn from(error: provider::ProviderError) -> TransactionSubmissionError {
+        TransactionSubmissionError::Provider(error)
     }
 }
\ No newline at end of file
@@ -0,0 +1,21 @@
+//! Middleware layers that wrap a [`crate::provider::Provider`] to add behavior
+//! (nonce management, retries, logging, ...) around a base provider, the same
+//! way ethers-rs stacks `Middleware`s around a base `Provider`.
+
+mod nonce_manager;
+pub use nonce_manager::NonceManager;
+
+use crate::provider::Provider;
+
+/// Extension trait for opting a [`Provider`] into middleware layers.
+pub trait ProviderExt: Provider + Sized {
+    /// Wraps `self` in a [`NonceManager`] that auto-assigns and retries nonces,
+    /// reading on-ledger state from `base_url`. Existing manual-nonce flows are
+    /// unaffected by simply not opting in: only providers wrapped this way have
+    /// their nonce managed automatically.
+    fn with_nonce_manager(self, base_url: impl Into<String>) -> NonceManager<Self> {
+        NonceManager::new(self, base_url)
+    }
+}
+
+impl<P: Provider> ProviderExt for P {}
@@ -0,0 +1,97 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use crate::provider::{Provider, ProviderError, Receipt, TransactionBody};
+
+/// Middleware that auto-assigns and retries nonces, modeled on ethers-rs's
+/// `NonceManagerMiddleware`.
+///
+/// Wraps an inner [`Provider`] and tracks the next nonce to use per signer in
+/// an `AtomicU64`. Every [`TransactionBody`] submitted through a `NonceManager`
+/// has its nonce overwritten with the managed value - opting out is as simple
+/// as not wrapping a provider with [`crate::middleware::ProviderExt::with_nonce_manager`],
+/// which leaves existing manual-nonce flows unaffected. Each signer's counter
+/// is initialized lazily, by reading the on-ledger value the first time that
+/// signer is seen, rather than eagerly on construction.
+pub struct NonceManager<P> {
+    inner: P,
+    base_url: String,
+    nonces: Mutex<HashMap<String, AtomicU64>>,
+}
+
+impl<P: Provider> NonceManager<P> {
+    pub fn new(inner: P, base_url: impl Into<String>) -> Self {
+        NonceManager {
+            inner,
+            base_url: base_url.into(),
+            nonces: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the next nonce to use for `signer`, initializing its counter
+    /// from the on-ledger value if this is the first time `signer` is seen.
+    pub fn next_nonce(&self, signer: &str) -> Result<u64, ProviderError> {
+        if let Some(counter) = self.nonces.lock().unwrap().get(signer) {
+            return Ok(counter.fetch_add(1, Ordering::SeqCst));
+        }
+
+        // Fetched outside the lock so one signer's first-ever lookup doesn't
+        // block every other signer's nonce request behind this network call.
+        let current = self.fetch_current_nonce(signer)?;
+
+        let mut nonces = self.nonces.lock().unwrap();
+        let counter = nonces.entry(signer.to_string()).or_insert_with(|| AtomicU64::new(current));
+        Ok(counter.fetch_add(1, Ordering::SeqCst))
+    }
+
+    /// Drops the cached nonce for `signer` so the next call to `next_nonce`
+    /// re-reads the on-ledger value instead of continuing the stale sequence.
+    fn resync(&self, signer: &str) {
+        self.nonces.lock().unwrap().remove(signer);
+    }
+
+    /// Reads the current nonce for `signer` from the ledger. A failed request
+    /// or an unparseable body is surfaced as a [`ProviderError`] rather than
+    /// silently defaulting to some placeholder nonce.
+    fn fetch_current_nonce(&self, signer: &str) -> Result<u64, ProviderError> {
+        let response = reqwest::blocking::get(format!("{}/nonce/{}", self.base_url, signer))?;
+        let body = response.text()?;
+        body.trim()
+            .parse()
+            .map_err(|_| ProviderError::JsonDeserializationError(body))
+    }
+}
+
+impl<P: Provider<Error = ProviderError>> Provider for NonceManager<P> {
+    type Error = ProviderError;
+
+    fn submit(&self, transaction: &TransactionBody) -> Result<Receipt, Self::Error> {
+        let signer = transaction.signatures.first().map(|s| s.public_key.as_str()).unwrap_or("");
+
+        let mut transaction = transaction.clone();
+        transaction.nonce.value = self.next_nonce(signer)?;
+
+        let receipt = self.inner.submit(&transaction)?;
+        if is_stale_nonce_rejection(&receipt) {
+            self.resync(signer);
+            transaction.nonce.value = self.next_nonce(signer)?;
+            return self.inner.submit(&transaction);
+        }
+
+        Ok(receipt)
+    }
+}
+
+/// Whether `receipt` reports the gateway rejected the transaction for using a
+/// stale/already-used nonce, in which case it's worth resyncing and retrying.
+///
+/// This only inspects a rejected receipt's own `status`/`logs` fields, never
+/// an opaque response body - a log unrelated to this submission can't match.
+fn is_stale_nonce_rejection(receipt: &Receipt) -> bool {
+    !receipt.is_success()
+        && receipt
+            .logs()
+            .iter()
+            .any(|(_, message)| message.to_lowercase().contains("nonce"))
+}
@@ -0,0 +1,232 @@
+//! Provider abstraction for submitting transactions to a PTE-compatible gateway.
+//!
+//! Modeled after ethers-rs's middleware architecture: a `Provider` is the thing
+//! that actually talks to a node/gateway, and middleware (see `middleware`)
+//! wraps a `Provider` to add behavior such as nonce management, retries, or
+//! logging without `submit_transaction` itself growing those concerns.
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::utils::DecompileError;
+
+/// Anything that can take a fully-formed [`TransactionBody`] and hand back a [`Receipt`].
+///
+/// Implementations are free to point at different hosts (PTE01, PTE02, a local
+/// gateway, ...), inject custom headers/timeouts, or be a middleware layer
+/// wrapping another `Provider`.
+pub trait Provider {
+    type Error: std::error::Error + 'static;
+
+    fn submit(&self, transaction: &TransactionBody) -> Result<Receipt, Self::Error>;
+}
+
+/// A [`Provider`] that submits transactions over HTTP to a PTE gateway's `/transaction` endpoint.
+pub struct PteHttpProvider {
+    pub base_url: String,
+    pub client: reqwest::blocking::Client,
+    pub async_client: reqwest::Client,
+}
+
+impl PteHttpProvider {
+    /// Creates a provider pointed at `base_url` (e.g. `https://pte01.radixdlt.com`)
+    /// using default-configured `reqwest` clients.
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self::with_clients(base_url, reqwest::blocking::Client::new(), reqwest::Client::new())
+    }
+
+    /// Creates a provider pointed at `base_url`, using a caller-supplied blocking
+    /// `client` so timeouts, headers, proxies, etc. can be customized. The async
+    /// client used by `submit_async`/`get_receipt`/`submit_and_confirm` is left
+    /// default-configured; use [`PteHttpProvider::with_clients`] to customize both.
+    pub fn with_client(base_url: impl Into<String>, client: reqwest::blocking::Client) -> Self {
+        Self::with_clients(base_url, client, reqwest::Client::new())
+    }
+
+    /// Creates a provider pointed at `base_url`, using caller-supplied blocking
+    /// and async clients so timeouts, headers, proxies, etc. can be customized
+    /// for both the sync [`Provider::submit`] path and the async confirm path.
+    pub fn with_clients(
+        base_url: impl Into<String>,
+        client: reqwest::blocking::Client,
+        async_client: reqwest::Client,
+    ) -> Self {
+        PteHttpProvider {
+            base_url: base_url.into(),
+            client,
+            async_client,
+        }
+    }
+
+    /// The PTE01 instance most of this crate's examples were written against.
+    pub fn pte01() -> Self {
+        Self::new("https://pte01.radixdlt.com")
+    }
+}
+
+impl Provider for PteHttpProvider {
+    type Error = ProviderError;
+
+    fn submit(&self, transaction: &TransactionBody) -> Result<Receipt, Self::Error> {
+        let response = self
+            .client
+            .post(format!("{}/transaction", self.base_url))
+            .json(transaction)
+            .send()?;
+
+        let response_body: String = response.text().unwrap();
+        serde_json::from_str(&response_body)
+            .map_err(|_| ProviderError::JsonDeserializationError(response_body))
+    }
+}
+
+impl PteHttpProvider {
+    /// The async counterpart of [`Provider::submit`], used by
+    /// `submit_transaction_and_confirm` so a single submission doesn't have to
+    /// block a whole OS thread while it's in flight.
+    pub async fn submit_async(&self, transaction: &TransactionBody) -> Result<Receipt, ProviderError> {
+        let response = self
+            .async_client
+            .post(format!("{}/transaction", self.base_url))
+            .json(transaction)
+            .send()
+            .await?;
+
+        let response_body: String = response.text().await.unwrap();
+        serde_json::from_str(&response_body)
+            .map_err(|_| ProviderError::JsonDeserializationError(response_body))
+    }
+
+    /// Fetches the current receipt for an already-submitted transaction, used
+    /// while polling for confirmation.
+    pub async fn get_receipt(&self, transaction_hash: &str) -> Result<Receipt, ProviderError> {
+        let response = self
+            .async_client
+            .get(format!("{}/transaction/{}", self.base_url, transaction_hash))
+            .send()
+            .await?;
+
+        let response_body: String = response.text().await.unwrap();
+        serde_json::from_str(&response_body)
+            .map_err(|_| ProviderError::JsonDeserializationError(response_body))
+    }
+
+    /// Submits `transaction`, then polls `get_receipt` on an exponential
+    /// backoff until the receipt's status is terminal, or `timeout` elapses.
+    pub async fn submit_and_confirm(
+        &self,
+        transaction: &TransactionBody,
+        timeout: Duration,
+    ) -> Result<Receipt, ProviderError> {
+        let mut receipt = self.submit_async(transaction).await?;
+        let deadline = std::time::Instant::now() + timeout;
+        let mut backoff = Duration::from_millis(250);
+
+        while !is_terminal_status(&receipt.status) {
+            if std::time::Instant::now() >= deadline {
+                return Err(ProviderError::Timeout(timeout));
+            }
+            tokio::time::sleep(backoff).await;
+            backoff = (backoff * 2).min(Duration::from_secs(5));
+            receipt = self.get_receipt(&receipt.transaction_hash).await?;
+        }
+
+        Ok(receipt)
+    }
+}
+
+/// Whether a receipt's `status` is final (committed one way or another) rather
+/// than still pending.
+fn is_terminal_status(status: &str) -> bool {
+    matches!(status.to_lowercase().as_str(), "success" | "failure" | "rejected")
+}
+
+/// Errors which can occur while a [`Provider`] submits a transaction.
+#[derive(Debug)]
+pub enum ProviderError {
+    DecompileError(DecompileError),
+    HttpRequestError(reqwest::Error),
+    JsonDeserializationError(String),
+    Timeout(Duration),
+}
+
+impl std::fmt::Display for ProviderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProviderError::DecompileError(error) => write!(f, "failed to decompile transaction manifest: {:?}", error),
+            ProviderError::HttpRequestError(error) => write!(f, "http request to provider failed: {}", error),
+            ProviderError::JsonDeserializationError(body) => write!(f, "could not deserialize provider response: {}", body),
+            ProviderError::Timeout(duration) => write!(f, "timed out after {:?} waiting for the provider", duration),
+        }
+    }
+}
+
+impl std::error::Error for ProviderError {}
+
+impl From<DecompileError> for ProviderError {
+    fn from(error: DecompileError) -> ProviderError {
+        ProviderError::DecompileError(error)
+    }
+}
+
+impl From<reqwest::Error> for ProviderError {
+    fn from(error: reqwest::Error) -> ProviderError {
+        ProviderError::HttpRequestError(error)
+    }
+}
+
+/// A struct which describes the Nonce. Required for the TransactionBody struct
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct Nonce {
+    pub value: u64,
+}
+
+/// A struct which defines the signature used in the TransactionBody struct.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct Signature {
+    pub public_key: String,
+    pub signature: String,
+}
+
+/// A struct which defines the transaction payload that the PTE's API accepts.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct TransactionBody {
+    pub manifest: String,
+    pub nonce: Nonce,
+    pub signatures: Vec<Signature>,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct Receipt {
+    pub transaction_hash: String,
+    pub status: String,
+    pub outputs: Vec<String>,
+    pub logs: Vec<String>,
+    pub new_packages: Vec<String>,
+    pub new_components: Vec<String>,
+    pub new_resources: Vec<String>,
+}
+
+impl Receipt {
+    pub fn new_packages(&self) -> Vec<scrypto::prelude::PackageAddress> {
+        return self.new_packages
+            .iter()
+            .map(|x| scrypto::prelude::PackageAddress::from_str(x).unwrap())
+            .collect()
+    }
+
+    pub fn new_components(&self) -> Vec<scrypto::prelude::ComponentAddress> {
+        return self.new_components
+            .iter()
+            .map(|x| scrypto::prelude::ComponentAddress::from_str(x).unwrap())
+            .collect()
+    }
+
+    pub fn new_resources(&self) -> Vec<scrypto::prelude::ResourceAddress> {
+        return self.new_resources
+            .iter()
+            .map(|x| scrypto::prelude::ResourceAddress::from_str(x).unwrap())
+            .collect()
+    }
+}